@@ -1,358 +1,1685 @@
-use std::collections::HashMap;
-use std::fmt;
-
-/// Represents a blockchain address
-/// 
-/// # Example
-/// ```
-/// use voting::Address;
-/// let addr = Address(vec![1, 2, 3]);
-/// assert_eq!(format!("{}", addr), "0x010203");
-/// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Address(pub Vec<u8>);
-
-impl fmt::Display for Address {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "0x{}", hex::encode(&self.0))
-    }
-}
-
-#[derive(Debug)]
-pub enum VotingError {
-    Unauthorized,
-    InvalidOption,
-    PollNotFound,
-    PollEnded,
-    AlreadyVoted,
-    PollInactive
-}
-
-#[derive(Debug, Clone, PartialEq, Eq)]  // Remove Hash derive
-pub struct Poll {
-    pub title: String,
-    pub description: String,
-    pub options: Vec<String>,
-    pub votes: HashMap<Address, usize>,
-    pub vote_counts: Vec<usize>, // Add this field
-    pub end_time: u64,
-    pub creator: Address,
-    pub is_active: bool,
-}
-
-/// Represents the main voting contract that manages polls and votes
-/// 
-/// # Example
-/// ```
-/// use voting::{VotingContract, Address};
-/// 
-/// let mut contract = VotingContract::new();
-/// let admin = Address(vec![1]);
-/// let voter = Address(vec![2]);
-/// 
-/// // Add new admin
-/// let default_admin = contract.admins[0].clone();
-/// contract.add_admin(&default_admin, admin.clone()).unwrap();
-/// 
-/// // Create a new poll
-/// let poll_id = contract.create_poll(
-///     &admin,
-///     "Best Programming Language".to_string(),
-///     "Vote for your favorite".to_string(),
-///     vec!["Rust".to_string(), "Go".to_string()],
-///     86400
-/// ).unwrap();
-/// 
-/// // Cast a vote
-/// contract.cast_vote(&voter, poll_id, 0).unwrap();
-/// 
-/// // Get results
-/// let results = contract.get_poll_results(poll_id).unwrap();
-/// assert_eq!(results[0], ("Rust".to_string(), 1));
-/// assert_eq!(results[1], ("Go".to_string(), 0));
-/// ```
-pub struct VotingContract {
-    pub admins: Vec<Address>,
-    polls: HashMap<u64, Poll>,
-    next_poll_id: u64
-}
-
-impl VotingContract {
-    /// Creates a new voting contract with a default admin
-    /// 
-    /// # Example
-    /// ```
-    /// use voting::VotingContract;
-    /// let contract = VotingContract::new();
-    /// assert_eq!(contract.admins.len(), 1);
-    /// ```
-    pub fn new() -> Self {
-        Self {
-            admins: vec![Address(vec![0])], // Default admin
-            polls: HashMap::new(),
-            next_poll_id: 1
-        }
-    }
-
-    /// Adds a new admin to the contract
-    /// 
-    /// # Example
-    /// ```
-    /// use voting::{VotingContract, Address};
-    /// let mut contract = VotingContract::new();
-    /// let new_admin = Address(vec![1]);
-    /// 
-    /// let default_admin = contract.admins[0].clone();
-    /// contract.add_admin(&default_admin, new_admin).unwrap();
-    /// assert_eq!(contract.admins.len(), 2);
-    /// ```
-    pub fn add_admin(&mut self, caller: &Address, new_admin: Address) -> Result<(), VotingError> {
-        if !self.admins.contains(caller) {
-            return Err(VotingError::Unauthorized);
-        }
-        self.admins.push(new_admin);
-        Ok(())
-    }
-
-    /// Creates a new poll with the given options
-    /// 
-    /// # Example
-    /// ```
-    /// use voting::{VotingContract, Address};
-    /// let mut contract = VotingContract::new();
-    /// let admin = contract.admins[0].clone();
-    /// 
-    /// let poll_id = contract.create_poll(
-    ///     &admin,
-    ///     "Favorite Color".to_string(),
-    ///     "Vote for your favorite color".to_string(),
-    ///     vec!["Blue".to_string(), "Red".to_string()],
-    ///     86400
-    /// ).unwrap();
-    /// 
-    /// let poll = contract.get_poll_details(poll_id).unwrap();
-    /// assert_eq!(poll.options.len(), 2);
-    /// ```
-    pub fn create_poll(
-        &mut self,
-        caller: &Address,
-        title: String,
-        description: String,
-        options: Vec<String>,
-        duration: u64,
-    ) -> Result<u64, VotingError> {
-        if !self.admins.contains(caller) {
-            return Err(VotingError::Unauthorized);
-        }
-
-        let poll_id = self.next_poll_id;
-        self.next_poll_id += 1;
-
-        let poll = Poll {
-            title,
-            description,
-            options: options.clone(),
-            votes: HashMap::new(),
-            vote_counts: vec![0; options.len()], // Initialize vote counts
-            end_time: duration,
-            creator: caller.clone(),
-            is_active: true
-        };
-
-        self.polls.insert(poll_id, poll);
-        Ok(poll_id)
-    }
-
-    pub fn end_poll(&mut self, caller: &Address, poll_id: u64) -> Result<(), VotingError> {
-        let poll = self.polls.get_mut(&poll_id).ok_or(VotingError::PollNotFound)?;
-        
-        if !self.admins.contains(caller) && &poll.creator != caller {
-            return Err(VotingError::Unauthorized);
-        }
-
-        poll.is_active = false;
-        Ok(())
-    }
-
-    pub fn cast_vote(&mut self, voter: &Address, poll_id: u64, option_idx: usize) -> Result<(), VotingError> {
-        let poll = self.polls.get_mut(&poll_id).ok_or(VotingError::PollNotFound)?;
-        
-        if !poll.is_active {
-            return Err(VotingError::PollInactive);
-        }
-
-        if poll.votes.contains_key(voter) {
-            return Err(VotingError::AlreadyVoted);
-        }
-
-        if option_idx >= poll.options.len() {
-            return Err(VotingError::InvalidOption);
-        }
-
-        poll.votes.insert(voter.clone(), option_idx);
-        poll.vote_counts[option_idx] += 1; // Update vote count
-        Ok(())
-    }
-
-    pub fn get_poll_results(&self, poll_id: u64) -> Result<Vec<(String, usize)>, VotingError> {
-        let poll = self.polls.get(&poll_id).ok_or(VotingError::PollNotFound)?;
-        Ok(poll.options.iter().cloned().zip(poll.vote_counts.iter().cloned()).collect())
-    }
-
-    pub fn get_active_polls(&self) -> Vec<(u64, &Poll)> {
-        self.polls
-            .iter()
-            .filter(|(_, poll)| poll.is_active)
-            .map(|(&id, poll)| (id, poll))
-            .collect()
-    }
-
-    pub fn get_voter_participation(&self, voter: &Address) -> usize {
-        self.polls
-            .values()
-            .filter(|poll| poll.votes.contains_key(voter))
-            .count()
-    }
-
-    pub fn get_poll_details(&self, poll_id: u64) -> Result<&Poll, VotingError> {
-        self.polls.get(&poll_id).ok_or(VotingError::PollNotFound)
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    fn create_test_address(val: u8) -> Address {
-        let mut bytes = vec![0; 20];
-        bytes[0] = val;
-        Address(bytes)
-    }
-
-    #[test]
-    fn test_create_poll() {
-        let mut contract = VotingContract::new();
-        let admin = create_test_address(1);
-        let default_admin = contract.admins[0].clone();
-        contract.add_admin(&default_admin, admin.clone()).unwrap();
-
-        let result = contract.create_poll(
-            &admin,
-            "Test Poll".to_string(),
-            "Description".to_string(),
-            vec!["Option A".to_string(), "Option B".to_string()],
-            86400,
-        ).unwrap();
-
-        assert_eq!(result, 1);
-        let poll = contract.get_poll_details(result).unwrap();
-        assert_eq!(poll.options.len(), 2);
-        assert_eq!(poll.vote_counts, vec![0, 0]);
-        assert!(poll.is_active);
-    }
-
-    #[test]
-    fn test_voting() {
-        let mut contract = VotingContract::new();
-        let admin = create_test_address(1);
-        let voter = create_test_address(2);
-        let default_admin = contract.admins[0].clone();
-        contract.add_admin(&default_admin, admin.clone()).unwrap();
-
-        let poll_id = contract.create_poll(
-            &admin,
-            "Test Poll".to_string(),
-            "Description".to_string(),
-            vec!["Option A".to_string(), "Option B".to_string()],
-            86400,
-        ).unwrap();
-
-        // Test successful vote
-        contract.cast_vote(&voter, poll_id, 0).unwrap();
-        let poll = contract.get_poll_details(poll_id).unwrap();
-        assert_eq!(poll.vote_counts[0], 1);
-        assert_eq!(poll.vote_counts[1], 0);
-
-        // Test duplicate vote
-        assert!(matches!(
-            contract.cast_vote(&voter, poll_id, 1),
-            Err(VotingError::AlreadyVoted)
-        ));
-    }
-
-    #[test]
-    fn test_end_poll() {
-        let mut contract = VotingContract::new();
-        let admin = create_test_address(1);
-        let voter = create_test_address(2);
-        let default_admin = contract.admins[0].clone();
-        contract.add_admin(&default_admin, admin.clone()).unwrap();
-
-        let poll_id = contract.create_poll(
-            &admin,
-            "Test Poll".to_string(),
-            "Description".to_string(),
-            vec!["Option A".to_string()],
-            86400,
-        ).unwrap();
-
-        contract.end_poll(&admin, poll_id).unwrap();
-        assert!(matches!(
-            contract.cast_vote(&voter, poll_id, 0),
-            Err(VotingError::PollInactive)
-        ));
-    }
-
-    #[test]
-    fn test_unauthorized_actions() {
-        let mut contract = VotingContract::new();
-        let non_admin = create_test_address(2);
-
-        // Test unauthorized poll creation
-        assert!(matches!(
-            contract.create_poll(
-                &non_admin,
-                "Test".to_string(),
-                "Test".to_string(),
-                vec!["Option".to_string()],
-                86400
-            ),
-            Err(VotingError::Unauthorized)
-        ));
-
-        // Test unauthorized admin addition
-        assert!(matches!(
-            contract.add_admin(&non_admin, create_test_address(3)),
-            Err(VotingError::Unauthorized)
-        ));
-    }
-
-    #[test]
-    fn test_get_active_polls() {
-        let mut contract = VotingContract::new();
-        let admin = create_test_address(1);
-        let default_admin = contract.admins[0].clone();
-        contract.add_admin(&default_admin, admin.clone()).unwrap();
-
-        let poll1_id = contract.create_poll(
-            &admin,
-            "Poll 1".to_string(),
-            "Description".to_string(),
-            vec!["Option".to_string()],
-            86400,
-        ).unwrap();
-
-        let poll2_id = contract.create_poll(
-            &admin,
-            "Poll 2".to_string(),
-            "Description".to_string(),
-            vec!["Option".to_string()],
-            86400,
-        ).unwrap();
-
-        contract.end_poll(&admin, poll2_id).unwrap();
-        let active_polls = contract.get_active_polls();
-        assert_eq!(active_polls.len(), 1);
-        assert_eq!(active_polls[0].0, poll1_id);
-    }
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// Base of the exponential lockout schedule: a vote confirmed `n` times
+/// holds its weight, and blocks re-affirming the same option, for
+/// `INITIAL_LOCKOUT.pow(n)` seconds.
+const INITIAL_LOCKOUT: u64 = 2;
+
+/// Maximum number of lockouts, expired or not, kept per voter per poll.
+/// Once a tower grows past this depth its oldest entries are dropped.
+const MAX_LOCKOUT_HISTORY: usize = 31;
+
+/// Seconds since the Unix epoch, used to evaluate lockout expiration.
+pub fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Represents a blockchain address
+/// 
+/// # Example
+/// ```
+/// use voting::Address;
+/// let addr = Address(vec![1, 2, 3]);
+/// assert_eq!(format!("{}", addr), "0x010203");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Address(pub Vec<u8>);
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(&self.0))
+    }
+}
+
+#[derive(Debug)]
+pub enum VotingError {
+    Unauthorized,
+    InvalidOption,
+    PollNotFound,
+    PollEnded,
+    AlreadyVoted,
+    PollInactive,
+    QuorumNotReached,
+    InsufficientApprovals,
+    DeserializationFailed,
+    VoteNotFound,
+    ReaffirmationTooSoon,
+    AdminNotFound,
+}
+
+/// A sensitive, multisig-gated operation. Proposed via `propose_action` and
+/// applied by `approve_action` once `required_approvals` distinct admins
+/// have signed off.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PendingAction {
+    AddAdmin(Address),
+    EndPoll(u64),
+    SetRequiredApprovals(usize),
+}
+
+/// Bookkeeping for a proposed `PendingAction` awaiting enough approvals.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PendingApproval {
+    action: PendingAction,
+    approvers: Vec<Address>,
+}
+
+/// A notification of contract activity, emitted to subscribed observers and
+/// appended to the buffered event log so external consumers (dashboards,
+/// webhooks, email sinks) can react without polling state directly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VotingEvent {
+    PollCreated { poll_id: u64, creator: Address },
+    VoteCast { poll_id: u64, voter: Address, option_idx: usize },
+    PollEnded { poll_id: u64 },
+    AdminAdded { admin: Address },
+}
+
+/// A subscriber callback invoked with each `VotingEvent` as it's emitted.
+pub type EventHandler = Box<dyn FnMut(&VotingEvent)>;
+
+/// The action a governance poll applies to the contract once it passes: a
+/// poll is either a plain opinion survey, or a self-executing proposal
+/// carrying one concrete admin-set mutation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProposalKind {
+    Opinion,
+    AddAdmin(Address),
+    RemoveAdmin(Address),
+    SwapAdmin { old: Address, new: Address },
+    ChangeThreshold(usize),
+}
+
+/// One entry in a voter's lockout tower: a vote for `option_idx`, confirmed
+/// `confirmation_count` times, locked out until `expiration_timestamp()`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lockout {
+    pub option_idx: usize,
+    pub confirmation_count: u32,
+    pub vote_timestamp: u64,
+}
+
+impl Lockout {
+    /// How long, in seconds, this vote keeps its weight and blocks
+    /// re-affirming the same option. Switching to another option is not
+    /// blocked.
+    pub fn lockout_period(&self) -> u64 {
+        INITIAL_LOCKOUT.pow(self.confirmation_count)
+    }
+
+    /// The timestamp at which this lockout releases.
+    pub fn expiration_timestamp(&self) -> u64 {
+        self.vote_timestamp + self.lockout_period()
+    }
+
+    pub fn is_expired(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.expiration_timestamp()
+    }
+
+    /// Weight contributed to the tally while this lockout is still active.
+    /// Doubles with every re-affirming confirmation.
+    pub fn effective_weight(&self) -> u64 {
+        1u64 << self.confirmation_count
+    }
+}
+
+/// The title, description, option labels, and real-time duration shared by
+/// every poll-creation entry point, bundled so those functions don't each
+/// need one parameter per field.
+#[derive(Debug, Clone)]
+pub struct PollContent {
+    pub title: String,
+    pub description: String,
+    pub options: Vec<String>,
+    pub duration: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]  // Remove Hash derive
+pub struct Poll {
+    pub title: String,
+    pub description: String,
+    pub options: Vec<String>,
+    pub votes: HashMap<Address, usize>,
+    pub vote_counts: Vec<usize>, // Add this field
+    pub end_time: u64,
+    pub creator: Address,
+    pub is_active: bool,
+    /// Per-voter stake-weighted lockout towers, oldest vote first. Separate
+    /// from `votes`/`vote_counts`, which still back the plain one-vote-per
+    /// `cast_vote` path.
+    pub vote_towers: HashMap<Address, VecDeque<Lockout>>,
+    /// The action this poll applies when it passes. Plain polls created via
+    /// `create_poll` carry `ProposalKind::Opinion`, for which `execute_poll`
+    /// changes nothing in the contract.
+    pub kind: ProposalKind,
+    /// Yes-tally required (option 0) for `execute_poll` to apply `kind`.
+    pub min_threshold: usize,
+    /// Set once `execute_poll` has successfully applied `kind`, so a passed
+    /// proposal can't be applied twice.
+    pub executed: bool,
+}
+
+/// Represents the main voting contract that manages polls and votes
+/// 
+/// # Example
+/// ```
+/// use voting::{VotingContract, Address, PollContent};
+///
+/// let mut contract = VotingContract::new();
+/// let admin = Address(vec![1]);
+/// let voter = Address(vec![2]);
+/// 
+/// // Add new admin
+/// let default_admin = contract.admins[0].clone();
+/// contract.add_admin(&default_admin, admin.clone()).unwrap();
+/// 
+/// // Create a new poll
+/// let poll_id = contract.create_poll(
+///     &admin,
+///     PollContent {
+///         title: "Best Programming Language".to_string(),
+///         description: "Vote for your favorite".to_string(),
+///         options: vec!["Rust".to_string(), "Go".to_string()],
+///         duration: 86400,
+///     },
+/// ).unwrap();
+/// 
+/// // Cast a vote
+/// contract.cast_vote(&voter, poll_id, 0).unwrap();
+/// 
+/// // Get results
+/// let results = contract.get_poll_results(poll_id).unwrap();
+/// assert_eq!(results[0], ("Rust".to_string(), 1));
+/// assert_eq!(results[1], ("Go".to_string(), 0));
+/// ```
+pub struct VotingContract {
+    pub admins: Vec<Address>,
+    polls: HashMap<u64, Poll>,
+    next_poll_id: u64,
+    /// Contract-wide floor on the yes-tally quorum `execute_poll` requires,
+    /// mutable in place by a passed `ProposalKind::ChangeThreshold`
+    /// proposal. A poll's own `min_threshold` can demand more than this but
+    /// never less, so raising it retroactively tightens every
+    /// not-yet-executed governance poll.
+    pub governance_threshold: usize,
+    /// Number of distinct admin approvals a `PendingAction` needs before
+    /// `approve_action` applies it.
+    pub required_approvals: usize,
+    pending_actions: HashMap<u64, PendingApproval>,
+    next_action_id: u64,
+    observers: Vec<EventHandler>,
+    event_log: Vec<VotingEvent>,
+}
+
+/// The persistent fields of `VotingContract` as of the `V0` snapshot shape.
+/// `observers` and `event_log` are runtime-only (a closure isn't
+/// serializable, and the log is a transient buffer) and are reset on
+/// restore rather than carried across a snapshot.
+#[derive(Serialize, Deserialize)]
+struct VotingContractStateV0 {
+    admins: Vec<Address>,
+    polls: HashMap<u64, Poll>,
+    next_poll_id: u64,
+    governance_threshold: usize,
+    required_approvals: usize,
+    pending_actions: HashMap<u64, PendingApproval>,
+    next_action_id: u64,
+}
+
+/// Versioned wrapper around the serialized contract state: new fields land
+/// in a new variant, with a conversion path from older variants so
+/// snapshots taken before the change keep restoring.
+#[derive(Serialize, Deserialize)]
+enum VotingContractState {
+    V0(VotingContractStateV0),
+}
+
+impl From<VotingContractState> for VotingContract {
+    fn from(state: VotingContractState) -> Self {
+        match state {
+            VotingContractState::V0(v0) => VotingContract {
+                admins: v0.admins,
+                polls: v0.polls,
+                next_poll_id: v0.next_poll_id,
+                governance_threshold: v0.governance_threshold,
+                required_approvals: v0.required_approvals,
+                pending_actions: v0.pending_actions,
+                next_action_id: v0.next_action_id,
+                observers: Vec::new(),
+                event_log: Vec::new(),
+            },
+        }
+    }
+}
+
+impl VotingContract {
+    /// Creates a new voting contract with a default admin
+    /// 
+    /// # Example
+    /// ```
+    /// use voting::VotingContract;
+    /// let contract = VotingContract::new();
+    /// assert_eq!(contract.admins.len(), 1);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            admins: vec![Address(vec![0])], // Default admin
+            polls: HashMap::new(),
+            next_poll_id: 1,
+            governance_threshold: 1,
+            required_approvals: 1,
+            pending_actions: HashMap::new(),
+            next_action_id: 1,
+            observers: Vec::new(),
+            event_log: Vec::new(),
+        }
+    }
+
+    /// Registers a callback invoked with every `VotingEvent` as it's
+    /// emitted. For consumers that prefer to poll a buffer instead of
+    /// holding a live callback, see `drain_event_log`.
+    pub fn subscribe(&mut self, handler: EventHandler) {
+        self.observers.push(handler);
+    }
+
+    /// Returns every event emitted since the last call, clearing the
+    /// buffered log.
+    pub fn drain_event_log(&mut self) -> Vec<VotingEvent> {
+        std::mem::take(&mut self.event_log)
+    }
+
+    /// Serializes the contract's persistent state (admins, polls, and
+    /// governance/multisig configuration) into a versioned, bincode-encoded
+    /// byte string suitable for off-chain storage.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let state = VotingContractState::V0(VotingContractStateV0 {
+            admins: self.admins.clone(),
+            polls: self.polls.clone(),
+            next_poll_id: self.next_poll_id,
+            governance_threshold: self.governance_threshold,
+            required_approvals: self.required_approvals,
+            pending_actions: self.pending_actions.clone(),
+            next_action_id: self.next_action_id,
+        });
+        bincode::serialize(&state).expect("VotingContractState always serializes")
+    }
+
+    /// Restores a contract from bytes produced by `snapshot`. Event
+    /// observers and the buffered event log are not part of a snapshot and
+    /// start empty on the restored contract.
+    pub fn restore(bytes: &[u8]) -> Result<Self, VotingError> {
+        let state: VotingContractState =
+            bincode::deserialize(bytes).map_err(|_| VotingError::DeserializationFailed)?;
+        Ok(state.into())
+    }
+
+    fn emit(&mut self, event: VotingEvent) {
+        for observer in self.observers.iter_mut() {
+            observer(&event);
+        }
+        self.event_log.push(event);
+    }
+
+    /// Adds a new admin to the contract. Adding an admin is a sensitive
+    /// action, so this is routed through the `propose_action`/
+    /// `approve_action` multisig queue under the hood: the mutation only
+    /// takes effect once `required_approvals` distinct admins have signed
+    /// off. With the default threshold of 1, a single admin's call still
+    /// applies immediately.
+    ///
+    /// # Example
+    /// ```
+    /// use voting::{VotingContract, Address};
+    /// let mut contract = VotingContract::new();
+    /// let new_admin = Address(vec![1]);
+    ///
+    /// let default_admin = contract.admins[0].clone();
+    /// contract.add_admin(&default_admin, new_admin).unwrap();
+    /// assert_eq!(contract.admins.len(), 2);
+    /// ```
+    pub fn add_admin(&mut self, caller: &Address, new_admin: Address) -> Result<(), VotingError> {
+        let action_id = self.propose_action(caller, PendingAction::AddAdmin(new_admin))?;
+        self.approve_action(caller, action_id)
+    }
+
+    /// Proposes a sensitive `PendingAction` for M-of-N admin sign-off.
+    /// Returns an action id to pass to `approve_action`; the action only
+    /// takes effect once `required_approvals` distinct admins approve it.
+    pub fn propose_action(&mut self, caller: &Address, action: PendingAction) -> Result<u64, VotingError> {
+        if !self.admins.contains(caller) {
+            return Err(VotingError::Unauthorized);
+        }
+
+        let action_id = self.next_action_id;
+        self.next_action_id += 1;
+        self.pending_actions.insert(action_id, PendingApproval { action, approvers: Vec::new() });
+        Ok(action_id)
+    }
+
+    /// Records `caller`'s approval of a pending action and applies it once
+    /// `required_approvals` distinct admins have signed off. Returns
+    /// `VotingError::InsufficientApprovals` while the action is still
+    /// short of that threshold (or if `action_id` doesn't exist). An applied
+    /// action is no longer pending, so approving it again changes nothing
+    /// and returns `VotingError::InsufficientApprovals`.
+    pub fn approve_action(&mut self, caller: &Address, action_id: u64) -> Result<(), VotingError> {
+        if !self.admins.contains(caller) {
+            return Err(VotingError::Unauthorized);
+        }
+
+        let required = self.required_approvals;
+        let pending = self.pending_actions.get_mut(&action_id).ok_or(VotingError::InsufficientApprovals)?;
+
+        if !pending.approvers.contains(caller) {
+            pending.approvers.push(caller.clone());
+        }
+
+        if pending.approvers.len() < required {
+            return Err(VotingError::InsufficientApprovals);
+        }
+
+        let action = self.pending_actions.remove(&action_id).unwrap().action;
+        self.apply_action(action);
+        Ok(())
+    }
+
+    /// Proposes a change to `required_approvals`. Like any other sensitive
+    /// action this only takes effect once `required_approvals` distinct
+    /// admins approve the returned action id via `approve_action`, so the
+    /// threshold can't be lowered by a single admin acting alone.
+    pub fn set_required_approvals(&mut self, caller: &Address, new_threshold: usize) -> Result<u64, VotingError> {
+        self.propose_action(caller, PendingAction::SetRequiredApprovals(new_threshold))
+    }
+
+    fn apply_action(&mut self, action: PendingAction) {
+        match action {
+            PendingAction::AddAdmin(addr) => {
+                if !self.admins.contains(&addr) {
+                    self.admins.push(addr.clone());
+                }
+                self.emit(VotingEvent::AdminAdded { admin: addr });
+            }
+            PendingAction::EndPoll(poll_id) => {
+                if let Some(poll) = self.polls.get_mut(&poll_id) {
+                    poll.is_active = false;
+                }
+                self.emit(VotingEvent::PollEnded { poll_id });
+            }
+            PendingAction::SetRequiredApprovals(new_threshold) => {
+                self.required_approvals = new_threshold;
+            }
+        }
+    }
+
+    /// Creates a new poll with the given options
+    ///
+    /// # Example
+    /// ```
+    /// use voting::{VotingContract, Address, PollContent};
+    /// let mut contract = VotingContract::new();
+    /// let admin = contract.admins[0].clone();
+    ///
+    /// let poll_id = contract.create_poll(
+    ///     &admin,
+    ///     PollContent {
+    ///         title: "Favorite Color".to_string(),
+    ///         description: "Vote for your favorite color".to_string(),
+    ///         options: vec!["Blue".to_string(), "Red".to_string()],
+    ///         duration: 86400,
+    ///     },
+    /// ).unwrap();
+    ///
+    /// let poll = contract.get_poll_details(poll_id).unwrap();
+    /// assert_eq!(poll.options.len(), 2);
+    /// ```
+    pub fn create_poll(&mut self, caller: &Address, content: PollContent) -> Result<u64, VotingError> {
+        self.create_poll_with_kind(caller, content, ProposalKind::Opinion, 0)
+    }
+
+    /// Creates a self-executing governance poll. Once the poll is ended and
+    /// its option-0 ("yes") tally reaches `min_threshold`, `execute_poll`
+    /// applies `kind` to the contract. Modeled on POA's typed ballots
+    /// (AddKey/RemoveKey/SwapKey/ChangeMinThreshold).
+    ///
+    /// # Example
+    /// ```
+    /// use voting::{VotingContract, Address, PollContent, ProposalKind};
+    /// let mut contract = VotingContract::new();
+    /// let admin = contract.admins[0].clone();
+    /// let candidate = Address(vec![9]);
+    ///
+    /// let poll_id = contract.create_governance_poll(
+    ///     &admin,
+    ///     PollContent {
+    ///         title: "Add a new admin".to_string(),
+    ///         description: "Should we add this address as an admin?".to_string(),
+    ///         options: vec!["Yes".to_string(), "No".to_string()],
+    ///         duration: 86400,
+    ///     },
+    ///     ProposalKind::AddAdmin(candidate.clone()),
+    ///     1,
+    /// ).unwrap();
+    ///
+    /// contract.cast_vote(&candidate, poll_id, 0).unwrap();
+    /// contract.end_poll(&admin, poll_id).unwrap();
+    /// contract.execute_poll(&admin, poll_id).unwrap();
+    /// assert!(contract.admins.contains(&candidate));
+    /// ```
+    pub fn create_governance_poll(
+        &mut self,
+        caller: &Address,
+        content: PollContent,
+        kind: ProposalKind,
+        min_threshold: usize,
+    ) -> Result<u64, VotingError> {
+        self.create_poll_with_kind(caller, content, kind, min_threshold)
+    }
+
+    fn create_poll_with_kind(
+        &mut self,
+        caller: &Address,
+        content: PollContent,
+        kind: ProposalKind,
+        min_threshold: usize,
+    ) -> Result<u64, VotingError> {
+        if !self.admins.contains(caller) {
+            return Err(VotingError::Unauthorized);
+        }
+
+        let PollContent { title, description, options, duration } = content;
+
+        let poll_id = self.next_poll_id;
+        self.next_poll_id += 1;
+
+        let poll = Poll {
+            title,
+            description,
+            options: options.clone(),
+            votes: HashMap::new(),
+            vote_counts: vec![0; options.len()], // Initialize vote counts
+            end_time: duration,
+            creator: caller.clone(),
+            is_active: true,
+            vote_towers: HashMap::new(),
+            kind,
+            min_threshold,
+            executed: false,
+        };
+
+        self.polls.insert(poll_id, poll);
+        self.emit(VotingEvent::PollCreated { poll_id, creator: caller.clone() });
+        Ok(poll_id)
+    }
+
+    /// Applies a passed governance poll's `ProposalKind` to the contract.
+    /// The poll must be ended with its option-0 ("yes") tally at or above
+    /// `min_threshold`, otherwise `VotingError::QuorumNotReached` is
+    /// returned. `governance_threshold` acts as a contract-wide floor on
+    /// top of the poll's own `min_threshold`: raising it via a passed
+    /// `ProposalKind::ChangeThreshold` proposal applies to every governance
+    /// poll executed afterwards, even ones created with a lower
+    /// `min_threshold`. Applying an already-executed poll is a no-op that
+    /// simply returns the stored `kind` again. A `RemoveAdmin`/`SwapAdmin`
+    /// whose target admin is no longer present returns
+    /// `VotingError::AdminNotFound` and leaves `executed` false, so the
+    /// proposal isn't silently burned and can still be executed later if
+    /// the admin set changes back.
+    pub fn execute_poll(&mut self, caller: &Address, poll_id: u64) -> Result<ProposalKind, VotingError> {
+        if !self.admins.contains(caller) {
+            return Err(VotingError::Unauthorized);
+        }
+
+        let yes_tally = self.get_poll_results(poll_id)?.first().map(|(_, count)| *count).unwrap_or(0);
+        let poll = self.polls.get_mut(&poll_id).ok_or(VotingError::PollNotFound)?;
+
+        if poll.executed {
+            return Ok(poll.kind.clone());
+        }
+
+        let required = poll.min_threshold.max(self.governance_threshold);
+        if poll.is_active || yes_tally < required {
+            return Err(VotingError::QuorumNotReached);
+        }
+
+        let kind = poll.kind.clone();
+
+        match &kind {
+            ProposalKind::RemoveAdmin(addr) if !self.admins.contains(addr) => {
+                return Err(VotingError::AdminNotFound);
+            }
+            ProposalKind::SwapAdmin { old, .. } if !self.admins.contains(old) => {
+                return Err(VotingError::AdminNotFound);
+            }
+            _ => {}
+        }
+
+        let poll = self.polls.get_mut(&poll_id).unwrap();
+        poll.executed = true;
+
+        match &kind {
+            ProposalKind::Opinion => {}
+            ProposalKind::AddAdmin(addr) => {
+                if !self.admins.contains(addr) {
+                    self.admins.push(addr.clone());
+                }
+            }
+            ProposalKind::RemoveAdmin(addr) => {
+                self.admins.retain(|a| a != addr);
+            }
+            ProposalKind::SwapAdmin { old, new } => {
+                if let Some(slot) = self.admins.iter_mut().find(|a| *a == old) {
+                    *slot = new.clone();
+                }
+            }
+            ProposalKind::ChangeThreshold(new_threshold) => {
+                self.governance_threshold = *new_threshold;
+            }
+        }
+
+        Ok(kind)
+    }
+
+    /// Ends a poll before its natural close. A poll's own creator may end
+    /// it unilaterally; any other admin ending a poll early is a sensitive
+    /// action routed through the `propose_action`/`approve_action` multisig
+    /// queue, so it only takes effect once `required_approvals` distinct
+    /// admins have signed off (immediately, with the default threshold of
+    /// 1).
+    pub fn end_poll(&mut self, caller: &Address, poll_id: u64) -> Result<(), VotingError> {
+        let poll = self.polls.get(&poll_id).ok_or(VotingError::PollNotFound)?;
+
+        if &poll.creator == caller {
+            let poll = self.polls.get_mut(&poll_id).unwrap();
+            poll.is_active = false;
+            self.emit(VotingEvent::PollEnded { poll_id });
+            return Ok(());
+        }
+
+        if !self.admins.contains(caller) {
+            return Err(VotingError::Unauthorized);
+        }
+
+        let action_id = self.propose_action(caller, PendingAction::EndPoll(poll_id))?;
+        self.approve_action(caller, action_id)
+    }
+
+    pub fn cast_vote(&mut self, voter: &Address, poll_id: u64, option_idx: usize) -> Result<(), VotingError> {
+        let poll = self.polls.get_mut(&poll_id).ok_or(VotingError::PollNotFound)?;
+        
+        if !poll.is_active {
+            return Err(VotingError::PollInactive);
+        }
+
+        if poll.votes.contains_key(voter) {
+            return Err(VotingError::AlreadyVoted);
+        }
+
+        if option_idx >= poll.options.len() {
+            return Err(VotingError::InvalidOption);
+        }
+
+        poll.votes.insert(voter.clone(), option_idx);
+        poll.vote_counts[option_idx] += 1; // Update vote count
+        self.emit(VotingEvent::VoteCast { poll_id, voter: voter.clone(), option_idx });
+        Ok(())
+    }
+
+    /// Switches a voter's existing choice to `new_option_idx` while the
+    /// poll is still open, letting the tally track changing intent instead
+    /// of locking a voter in at their first `cast_vote`.
+    pub fn change_vote(&mut self, voter: &Address, poll_id: u64, new_option_idx: usize) -> Result<(), VotingError> {
+        let poll = self.polls.get_mut(&poll_id).ok_or(VotingError::PollNotFound)?;
+
+        if !poll.is_active {
+            return Err(VotingError::PollInactive);
+        }
+
+        if new_option_idx >= poll.options.len() {
+            return Err(VotingError::InvalidOption);
+        }
+
+        let previous_option_idx = *poll.votes.get(voter).ok_or(VotingError::VoteNotFound)?;
+
+        poll.vote_counts[previous_option_idx] -= 1;
+        poll.vote_counts[new_option_idx] += 1;
+        poll.votes.insert(voter.clone(), new_option_idx);
+
+        self.emit(VotingEvent::VoteCast { poll_id, voter: voter.clone(), option_idx: new_option_idx });
+        Ok(())
+    }
+
+    /// Removes a voter's choice entirely while the poll is still open,
+    /// decrementing its tally. Errors with `VotingError::VoteNotFound` if
+    /// the voter never cast a vote on this poll.
+    pub fn withdraw_vote(&mut self, voter: &Address, poll_id: u64) -> Result<(), VotingError> {
+        let poll = self.polls.get_mut(&poll_id).ok_or(VotingError::PollNotFound)?;
+
+        if !poll.is_active {
+            return Err(VotingError::PollInactive);
+        }
+
+        let option_idx = poll.votes.remove(voter).ok_or(VotingError::VoteNotFound)?;
+        poll.vote_counts[option_idx] -= 1;
+        Ok(())
+    }
+
+    /// Casts (or re-affirms) a stake-weighted vote, holding a `Lockout` in
+    /// the voter's per-poll tower. Re-affirming the same option only
+    /// succeeds once the tower's current lockout has actually expired,
+    /// doubling its effective weight and its lockout period for next time;
+    /// calling again before that expiration is rejected with
+    /// `VotingError::ReaffirmationTooSoon`, so growing weight genuinely
+    /// requires waiting out an exponentially lengthening real-time delay
+    /// rather than calling in a tight loop. Switching to a different
+    /// `option_idx` resets the tower instead of letting old and new weight
+    /// coexist, so a voter can only ever be backing one option at a time.
+    pub fn cast_weighted_vote(&mut self, voter: &Address, poll_id: u64, option_idx: usize) -> Result<(), VotingError> {
+        let poll = self.polls.get_mut(&poll_id).ok_or(VotingError::PollNotFound)?;
+
+        if !poll.is_active {
+            return Err(VotingError::PollInactive);
+        }
+
+        if option_idx >= poll.options.len() {
+            return Err(VotingError::InvalidOption);
+        }
+
+        let now = current_timestamp();
+        let tower = poll.vote_towers.entry(voter.clone()).or_insert_with(VecDeque::new);
+
+        match tower.back_mut() {
+            Some(top) if top.option_idx == option_idx => {
+                if !top.is_expired(now) {
+                    return Err(VotingError::ReaffirmationTooSoon);
+                }
+                top.confirmation_count += 1;
+                top.vote_timestamp = now;
+            }
+            _ => {
+                tower.clear();
+                tower.push_back(Lockout {
+                    option_idx,
+                    confirmation_count: 0,
+                    vote_timestamp: now,
+                });
+            }
+        }
+
+        while tower.len() > MAX_LOCKOUT_HISTORY {
+            tower.pop_front();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the voter's current lockout tower for a poll, oldest vote
+    /// first, with already-expired entries removed.
+    pub fn get_lockout_status(&self, poll_id: u64, voter: &Address) -> Result<Vec<Lockout>, VotingError> {
+        let poll = self.polls.get(&poll_id).ok_or(VotingError::PollNotFound)?;
+        let now = current_timestamp();
+        Ok(poll
+            .vote_towers
+            .get(voter)
+            .into_iter()
+            .flatten()
+            .filter(|lockout| !lockout.is_expired(now))
+            .cloned()
+            .collect())
+    }
+
+    pub fn get_poll_results(&self, poll_id: u64) -> Result<Vec<(String, usize)>, VotingError> {
+        let poll = self.polls.get(&poll_id).ok_or(VotingError::PollNotFound)?;
+        let now = current_timestamp();
+
+        let mut counts = poll.vote_counts.clone();
+        for tower in poll.vote_towers.values() {
+            for lockout in tower {
+                if !lockout.is_expired(now) {
+                    counts[lockout.option_idx] += lockout.effective_weight() as usize;
+                }
+            }
+        }
+
+        Ok(poll.options.iter().cloned().zip(counts).collect())
+    }
+
+    pub fn get_active_polls(&self) -> Vec<(u64, &Poll)> {
+        self.polls
+            .iter()
+            .filter(|(_, poll)| poll.is_active)
+            .map(|(&id, poll)| (id, poll))
+            .collect()
+    }
+
+    pub fn get_voter_participation(&self, voter: &Address) -> usize {
+        self.polls
+            .values()
+            .filter(|poll| poll.votes.contains_key(voter))
+            .count()
+    }
+
+    pub fn get_poll_details(&self, poll_id: u64) -> Result<&Poll, VotingError> {
+        self.polls.get(&poll_id).ok_or(VotingError::PollNotFound)
+    }
+
+    /// Aggregates a single poll's `get_poll_results` into turnout and
+    /// margin-of-victory figures for reporting.
+    pub fn poll_stats(&self, poll_id: u64) -> Result<PollStats, VotingError> {
+        let poll = self.polls.get(&poll_id).ok_or(VotingError::PollNotFound)?;
+        let results = self.get_poll_results(poll_id)?;
+        let total_votes: usize = results.iter().map(|(_, count)| *count).sum();
+
+        let option_shares = results
+            .iter()
+            .map(|(name, count)| {
+                let share = if total_votes == 0 { 0.0 } else { *count as f64 / total_votes as f64 };
+                (name.clone(), share)
+            })
+            .collect();
+
+        let mut sorted_counts: Vec<usize> = results.iter().map(|(_, count)| *count).collect();
+        sorted_counts.sort_unstable_by(|a, b| b.cmp(a));
+        let margin = match (sorted_counts.first(), sorted_counts.get(1)) {
+            (Some(top), Some(runner_up)) => top.saturating_sub(*runner_up),
+            (Some(top), None) => *top,
+            _ => 0,
+        };
+
+        let winning_option = results
+            .iter()
+            .max_by_key(|(_, count)| *count)
+            .filter(|_| total_votes > 0)
+            .map(|(name, _)| name.clone());
+
+        let yes_tally = results.first().map(|(_, count)| *count).unwrap_or(0);
+
+        Ok(PollStats {
+            poll_id,
+            total_votes,
+            option_shares,
+            winning_option,
+            margin,
+            quorum_met: yes_tally >= poll.min_threshold.max(self.governance_threshold),
+        })
+    }
+
+    /// Aggregates turnout and participation figures across every stored
+    /// poll. Turnout comes from `get_poll_results` (so it is weighted); a
+    /// unique voter is any address holding a plain vote or a weighted-vote
+    /// tower on at least one poll.
+    pub fn global_stats(&self) -> GlobalStats {
+        let total_polls = self.polls.len();
+        let active_polls = self.polls.values().filter(|poll| poll.is_active).count();
+
+        let mut unique_voters: HashSet<&Address> = HashSet::new();
+        let mut total_turnout = 0usize;
+        for (&poll_id, poll) in self.polls.iter() {
+            unique_voters.extend(poll.votes.keys());
+            unique_voters.extend(poll.vote_towers.keys());
+            if let Ok(results) = self.get_poll_results(poll_id) {
+                total_turnout += results.iter().map(|(_, count)| count).sum::<usize>();
+            }
+        }
+
+        let average_turnout = if total_polls == 0 { 0.0 } else { total_turnout as f64 / total_polls as f64 };
+
+        GlobalStats {
+            total_polls,
+            active_polls,
+            ended_polls: total_polls - active_polls,
+            unique_voters: unique_voters.len(),
+            average_turnout,
+        }
+    }
+}
+
+/// Turnout and margin-of-victory report for a single poll, built from
+/// `get_poll_results`. See `VotingContract::poll_stats`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PollStats {
+    pub poll_id: u64,
+    pub total_votes: usize,
+    /// Each option's name and its share of `total_votes`, 0.0 when nobody
+    /// has voted yet.
+    pub option_shares: Vec<(String, f64)>,
+    pub winning_option: Option<String>,
+    /// Votes separating the winner from the runner-up.
+    pub margin: usize,
+    /// Whether the option-0 ("yes") tally met the larger of the poll's
+    /// `min_threshold` and the contract's `governance_threshold`.
+    pub quorum_met: bool,
+}
+
+impl PollStats {
+    pub fn to_json(&self) -> String {
+        let shares = self
+            .option_shares
+            .iter()
+            .map(|(name, share)| format!("{{\"option\":\"{}\",\"share\":{}}}", json_escape(name), share))
+            .collect::<Vec<_>>()
+            .join(",");
+        let winning_option = match &self.winning_option {
+            Some(name) => format!("\"{}\"", json_escape(name)),
+            None => "null".to_string(),
+        };
+
+        format!(
+            "{{\"poll_id\":{},\"total_votes\":{},\"option_shares\":[{}],\"winning_option\":{},\"margin\":{},\"quorum_met\":{}}}",
+            self.poll_id, self.total_votes, shares, winning_option, self.margin, self.quorum_met
+        )
+    }
+}
+
+/// Turnout and participation report across every poll in a contract. See
+/// `VotingContract::global_stats`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalStats {
+    pub total_polls: usize,
+    pub active_polls: usize,
+    pub ended_polls: usize,
+    pub unique_voters: usize,
+    pub average_turnout: f64,
+}
+
+impl GlobalStats {
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"total_polls\":{},\"active_polls\":{},\"ended_polls\":{},\"unique_voters\":{},\"average_turnout\":{}}}",
+            self.total_polls, self.active_polls, self.ended_polls, self.unique_voters, self.average_turnout
+        )
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_address(val: u8) -> Address {
+        let mut bytes = vec![0; 20];
+        bytes[0] = val;
+        Address(bytes)
+    }
+
+    #[test]
+    fn test_create_poll() {
+        let mut contract = VotingContract::new();
+        let admin = create_test_address(1);
+        let default_admin = contract.admins[0].clone();
+        contract.add_admin(&default_admin, admin.clone()).unwrap();
+
+        let result = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string(), "Option B".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        assert_eq!(result, 1);
+        let poll = contract.get_poll_details(result).unwrap();
+        assert_eq!(poll.options.len(), 2);
+        assert_eq!(poll.vote_counts, vec![0, 0]);
+        assert!(poll.is_active);
+    }
+
+    #[test]
+    fn test_voting() {
+        let mut contract = VotingContract::new();
+        let admin = create_test_address(1);
+        let voter = create_test_address(2);
+        let default_admin = contract.admins[0].clone();
+        contract.add_admin(&default_admin, admin.clone()).unwrap();
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string(), "Option B".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        // Test successful vote
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        let poll = contract.get_poll_details(poll_id).unwrap();
+        assert_eq!(poll.vote_counts[0], 1);
+        assert_eq!(poll.vote_counts[1], 0);
+
+        // Test duplicate vote
+        assert!(matches!(
+            contract.cast_vote(&voter, poll_id, 1),
+            Err(VotingError::AlreadyVoted)
+        ));
+    }
+
+    #[test]
+    fn test_end_poll() {
+        let mut contract = VotingContract::new();
+        let admin = create_test_address(1);
+        let voter = create_test_address(2);
+        let default_admin = contract.admins[0].clone();
+        contract.add_admin(&default_admin, admin.clone()).unwrap();
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        contract.end_poll(&admin, poll_id).unwrap();
+        assert!(matches!(
+            contract.cast_vote(&voter, poll_id, 0),
+            Err(VotingError::PollInactive)
+        ));
+    }
+
+    #[test]
+    fn test_unauthorized_actions() {
+        let mut contract = VotingContract::new();
+        let non_admin = create_test_address(2);
+
+        // Test unauthorized poll creation
+        assert!(matches!(
+            contract.create_poll(
+                &non_admin,
+                PollContent {
+                    title: "Test".to_string(),
+                    description: "Test".to_string(),
+                    options: vec!["Option".to_string()],
+                    duration: 86400,
+                },
+            ),
+            Err(VotingError::Unauthorized)
+        ));
+
+        // Test unauthorized admin addition
+        assert!(matches!(
+            contract.add_admin(&non_admin, create_test_address(3)),
+            Err(VotingError::Unauthorized)
+        ));
+    }
+
+    #[test]
+    fn test_get_active_polls() {
+        let mut contract = VotingContract::new();
+        let admin = create_test_address(1);
+        let default_admin = contract.admins[0].clone();
+        contract.add_admin(&default_admin, admin.clone()).unwrap();
+
+        let poll1_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Poll 1".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        let poll2_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Poll 2".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        contract.end_poll(&admin, poll2_id).unwrap();
+        let active_polls = contract.get_active_polls();
+        assert_eq!(active_polls.len(), 1);
+        assert_eq!(active_polls[0].0, poll1_id);
+    }
+
+    #[test]
+    fn test_weighted_vote_confirmation_doubles_weight() {
+        let mut contract = VotingContract::new();
+        let admin = create_test_address(1);
+        let voter = create_test_address(2);
+        let default_admin = contract.admins[0].clone();
+        contract.add_admin(&default_admin, admin.clone()).unwrap();
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string(), "Option B".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        // A single weighted vote carries a confirmation count of 0, so it
+        // contributes a weight of 1.
+        contract.cast_weighted_vote(&voter, poll_id, 0).unwrap();
+        let results = contract.get_poll_results(poll_id).unwrap();
+        assert_eq!(results[0].1, 1);
+
+        // Re-affirming before the current lockout actually expires is
+        // rejected rather than silently doubling the weight.
+        assert!(matches!(
+            contract.cast_weighted_vote(&voter, poll_id, 0),
+            Err(VotingError::ReaffirmationTooSoon)
+        ));
+        let results = contract.get_poll_results(poll_id).unwrap();
+        assert_eq!(results[0].1, 1);
+
+        // Once the lockout has genuinely expired, re-affirming bumps its
+        // confirmation count in place, doubling its weight.
+        std::thread::sleep(std::time::Duration::from_secs(1));
+        contract.cast_weighted_vote(&voter, poll_id, 0).unwrap();
+        let results = contract.get_poll_results(poll_id).unwrap();
+        assert_eq!(results[0].1, 2);
+
+        let tower = contract.get_lockout_status(poll_id, &voter).unwrap();
+        assert_eq!(tower.len(), 1);
+        assert_eq!(tower[0].confirmation_count, 1);
+    }
+
+    #[test]
+    fn test_weighted_vote_switching_option_resets_tower() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string(), "Option B".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        contract.cast_weighted_vote(&voter, poll_id, 0).unwrap();
+
+        // Switching allegiance to a different option isn't rate-limited
+        // (there's no accumulated weight to protect), and it roots the old
+        // tower instead of letting its weight coexist with the new one.
+        contract.cast_weighted_vote(&voter, poll_id, 1).unwrap();
+
+        let results = contract.get_poll_results(poll_id).unwrap();
+        assert_eq!(results[0].1, 0);
+        assert_eq!(results[1].1, 1);
+
+        let tower = contract.get_lockout_status(poll_id, &voter).unwrap();
+        assert_eq!(tower.len(), 1);
+        assert_eq!(tower[0].option_idx, 1);
+    }
+
+    #[test]
+    fn test_weighted_vote_rejects_invalid_option_and_inactive_poll() {
+        let mut contract = VotingContract::new();
+        let admin = create_test_address(1);
+        let voter = create_test_address(2);
+        let default_admin = contract.admins[0].clone();
+        contract.add_admin(&default_admin, admin.clone()).unwrap();
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        assert!(matches!(
+            contract.cast_weighted_vote(&voter, poll_id, 5),
+            Err(VotingError::InvalidOption)
+        ));
+
+        contract.end_poll(&admin, poll_id).unwrap();
+        assert!(matches!(
+            contract.cast_weighted_vote(&voter, poll_id, 0),
+            Err(VotingError::PollInactive)
+        ));
+    }
+
+    #[test]
+    fn test_governance_poll_executes_when_quorum_met() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+        let candidate = create_test_address(9);
+
+        let poll_id = contract.create_governance_poll(
+            &admin,
+            PollContent {
+                title: "Add a new admin".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Yes".to_string(), "No".to_string()],
+                duration: 86400,
+            },
+            ProposalKind::AddAdmin(candidate.clone()),
+            1,
+        ).unwrap();
+
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        contract.end_poll(&admin, poll_id).unwrap();
+
+        let applied = contract.execute_poll(&admin, poll_id).unwrap();
+        assert_eq!(applied, ProposalKind::AddAdmin(candidate.clone()));
+        assert!(contract.admins.contains(&candidate));
+
+        // Idempotent: executing again doesn't push a duplicate admin.
+        contract.execute_poll(&admin, poll_id).unwrap();
+        assert_eq!(contract.admins.iter().filter(|a| *a == &candidate).count(), 1);
+    }
+
+    #[test]
+    fn test_governance_poll_rejects_execution_below_quorum_or_while_active() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let candidate = create_test_address(9);
+
+        let poll_id = contract.create_governance_poll(
+            &admin,
+            PollContent {
+                title: "Add a new admin".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Yes".to_string(), "No".to_string()],
+                duration: 86400,
+            },
+            ProposalKind::AddAdmin(candidate.clone()),
+            1,
+        ).unwrap();
+
+        // Still active: rejected.
+        assert!(matches!(
+            contract.execute_poll(&admin, poll_id),
+            Err(VotingError::QuorumNotReached)
+        ));
+
+        contract.end_poll(&admin, poll_id).unwrap();
+
+        // Ended, but no votes: quorum not met.
+        assert!(matches!(
+            contract.execute_poll(&admin, poll_id),
+            Err(VotingError::QuorumNotReached)
+        ));
+        assert!(!contract.admins.contains(&candidate));
+    }
+
+    #[test]
+    fn test_execute_poll_rejects_remove_admin_for_absent_target_without_burning_it() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+        let target = create_test_address(9);
+
+        let poll_id = contract.create_governance_poll(
+            &admin,
+            PollContent {
+                title: "Remove an admin".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Yes".to_string(), "No".to_string()],
+                duration: 86400,
+            },
+            ProposalKind::RemoveAdmin(target.clone()),
+            1,
+        ).unwrap();
+
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        contract.end_poll(&admin, poll_id).unwrap();
+
+        // `target` was never an admin, so applying the proposal fails instead
+        // of silently succeeding and burning it.
+        assert!(matches!(
+            contract.execute_poll(&admin, poll_id),
+            Err(VotingError::AdminNotFound)
+        ));
+        assert!(!contract.get_poll_details(poll_id).unwrap().executed);
+
+        // Once `target` is actually an admin, the same poll can still execute.
+        contract.add_admin(&admin, target.clone()).unwrap();
+        let applied = contract.execute_poll(&admin, poll_id).unwrap();
+        assert_eq!(applied, ProposalKind::RemoveAdmin(target.clone()));
+        assert!(!contract.admins.contains(&target));
+    }
+
+    #[test]
+    fn test_change_threshold_raises_the_quorum_floor_for_future_polls() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+        let candidate = create_test_address(9);
+
+        let raise_threshold_id = contract.create_governance_poll(
+            &admin,
+            PollContent {
+                title: "Raise the governance threshold".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Yes".to_string(), "No".to_string()],
+                duration: 86400,
+            },
+            ProposalKind::ChangeThreshold(2),
+            1,
+        ).unwrap();
+        contract.cast_vote(&voter, raise_threshold_id, 0).unwrap();
+        contract.end_poll(&admin, raise_threshold_id).unwrap();
+        contract.execute_poll(&admin, raise_threshold_id).unwrap();
+        assert_eq!(contract.governance_threshold, 2);
+
+        // A later poll asking for only 1 yes vote is still held to the new,
+        // higher contract-wide floor even though its own min_threshold is 1.
+        let poll_id = contract.create_governance_poll(
+            &admin,
+            PollContent {
+                title: "Add a new admin".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Yes".to_string(), "No".to_string()],
+                duration: 86400,
+            },
+            ProposalKind::AddAdmin(candidate.clone()),
+            1,
+        ).unwrap();
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        contract.end_poll(&admin, poll_id).unwrap();
+
+        assert!(matches!(
+            contract.execute_poll(&admin, poll_id),
+            Err(VotingError::QuorumNotReached)
+        ));
+        assert!(!contract.admins.contains(&candidate));
+    }
+
+    #[test]
+    fn test_multisig_requires_distinct_admin_approvals() {
+        let mut contract = VotingContract::new();
+        let admin_a = contract.admins[0].clone();
+        let admin_b = create_test_address(2);
+        let admin_c = create_test_address(3);
+        contract.add_admin(&admin_a, admin_b.clone()).unwrap();
+        contract.add_admin(&admin_a, admin_c.clone()).unwrap();
+
+        // With the default threshold of 1, a lone admin's approval applies
+        // the action immediately.
+        let action_id = contract
+            .propose_action(&admin_a, PendingAction::SetRequiredApprovals(2))
+            .unwrap();
+        contract.approve_action(&admin_a, action_id).unwrap();
+        assert_eq!(contract.required_approvals, 2);
+
+        let candidate = create_test_address(9);
+        let action_id = contract
+            .propose_action(&admin_a, PendingAction::AddAdmin(candidate.clone()))
+            .unwrap();
+        assert!(matches!(
+            contract.approve_action(&admin_a, action_id),
+            Err(VotingError::InsufficientApprovals)
+        ));
+        assert!(!contract.admins.contains(&candidate));
+
+        contract.approve_action(&admin_c, action_id).unwrap();
+        assert!(contract.admins.contains(&candidate));
+    }
+
+    #[test]
+    fn test_add_admin_and_end_poll_cannot_bypass_multisig_threshold() {
+        let mut contract = VotingContract::new();
+        let admin_a = contract.admins[0].clone();
+        let admin_b = create_test_address(2);
+        contract.add_admin(&admin_a, admin_b.clone()).unwrap();
+
+        let action_id = contract
+            .propose_action(&admin_a, PendingAction::SetRequiredApprovals(2))
+            .unwrap();
+        contract.approve_action(&admin_a, action_id).unwrap();
+        assert_eq!(contract.required_approvals, 2);
+
+        // A lone admin can no longer add an admin outright.
+        let candidate = create_test_address(9);
+        assert!(matches!(
+            contract.add_admin(&admin_a, candidate.clone()),
+            Err(VotingError::InsufficientApprovals)
+        ));
+        assert!(!contract.admins.contains(&candidate));
+
+        // Nor can a lone non-creator admin end someone else's poll outright.
+        let poll_id = contract.create_poll(
+            &admin_a,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+        assert!(matches!(
+            contract.end_poll(&admin_b, poll_id),
+            Err(VotingError::InsufficientApprovals)
+        ));
+        assert!(contract.get_poll_details(poll_id).unwrap().is_active);
+
+        // The poll's own creator can still end it unilaterally.
+        contract.end_poll(&admin_a, poll_id).unwrap();
+        assert!(!contract.get_poll_details(poll_id).unwrap().is_active);
+    }
+
+    #[test]
+    fn test_approve_action_rejects_unknown_action_id() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        assert!(matches!(
+            contract.approve_action(&admin, 999),
+            Err(VotingError::InsufficientApprovals)
+        ));
+    }
+
+    #[test]
+    fn test_subscribe_receives_lifecycle_events() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+
+        let seen: Rc<RefCell<Vec<VotingEvent>>> = Rc::new(RefCell::new(Vec::new()));
+        let seen_handle = seen.clone();
+        contract.subscribe(Box::new(move |event| seen_handle.borrow_mut().push(event.clone())));
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        contract.end_poll(&admin, poll_id).unwrap();
+        contract.add_admin(&admin, voter.clone()).unwrap();
+
+        assert_eq!(
+            *seen.borrow(),
+            vec![
+                VotingEvent::PollCreated { poll_id, creator: admin.clone() },
+                VotingEvent::VoteCast { poll_id, voter: voter.clone(), option_idx: 0 },
+                VotingEvent::PollEnded { poll_id },
+                VotingEvent::AdminAdded { admin: voter },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drain_event_log_buffers_and_clears() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+
+        contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        let drained = contract.drain_event_log();
+        assert_eq!(drained.len(), 1);
+        assert!(contract.drain_event_log().is_empty());
+    }
+
+    #[test]
+    fn test_snapshot_restore_round_trip_mid_voting() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter_a = create_test_address(2);
+        let voter_b = create_test_address(3);
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string(), "Option B".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+        contract.cast_vote(&voter_a, poll_id, 0).unwrap();
+        contract.cast_weighted_vote(&voter_b, poll_id, 1).unwrap();
+
+        let bytes = contract.snapshot();
+        let restored = VotingContract::restore(&bytes).unwrap();
+
+        assert_eq!(restored.admins, contract.admins);
+        assert_eq!(restored.get_poll_details(poll_id).unwrap(), contract.get_poll_details(poll_id).unwrap());
+        assert_eq!(restored.get_poll_results(poll_id).unwrap(), contract.get_poll_results(poll_id).unwrap());
+    }
+
+    #[test]
+    fn test_restore_rejects_garbage_bytes() {
+        assert!(matches!(
+            VotingContract::restore(&[1, 2, 3, 4]),
+            Err(VotingError::DeserializationFailed)
+        ));
+    }
+
+    #[test]
+    fn test_poll_stats_reports_winner_and_margin() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter_a = create_test_address(2);
+        let voter_b = create_test_address(3);
+        let voter_c = create_test_address(4);
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string(), "Option B".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+        contract.cast_vote(&voter_a, poll_id, 0).unwrap();
+        contract.cast_vote(&voter_b, poll_id, 0).unwrap();
+        contract.cast_vote(&voter_c, poll_id, 1).unwrap();
+
+        let stats = contract.poll_stats(poll_id).unwrap();
+        assert_eq!(stats.total_votes, 3);
+        assert_eq!(stats.winning_option, Some("Option A".to_string()));
+        assert_eq!(stats.margin, 1);
+        assert!((stats.option_shares[0].1 - 2.0 / 3.0).abs() < f64::EPSILON);
+        assert!(stats.to_json().contains("\"winning_option\":\"Option A\""));
+    }
+
+    #[test]
+    fn test_poll_stats_quorum_met_respects_governance_threshold_floor() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+        let candidate = create_test_address(9);
+
+        // Raise the contract-wide floor above this poll's own min_threshold.
+        let raise_threshold_id = contract.create_governance_poll(
+            &admin,
+            PollContent {
+                title: "Raise threshold".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Yes".to_string(), "No".to_string()],
+                duration: 86400,
+            },
+            ProposalKind::ChangeThreshold(5),
+            1,
+        ).unwrap();
+        contract.cast_vote(&voter, raise_threshold_id, 0).unwrap();
+        contract.end_poll(&admin, raise_threshold_id).unwrap();
+        contract.execute_poll(&admin, raise_threshold_id).unwrap();
+        assert_eq!(contract.governance_threshold, 5);
+
+        let poll_id = contract.create_governance_poll(
+            &admin,
+            PollContent {
+                title: "Add a new admin".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Yes".to_string(), "No".to_string()],
+                duration: 86400,
+            },
+            ProposalKind::AddAdmin(candidate.clone()),
+            1,
+        ).unwrap();
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        contract.end_poll(&admin, poll_id).unwrap();
+
+        // Only 1 yes-vote: meets the poll's own min_threshold of 1, but not
+        // the raised governance_threshold floor of 5, so poll_stats must
+        // agree with execute_poll that quorum isn't met.
+        let stats = contract.poll_stats(poll_id).unwrap();
+        assert!(!stats.quorum_met);
+        assert!(matches!(
+            contract.execute_poll(&admin, poll_id),
+            Err(VotingError::QuorumNotReached)
+        ));
+    }
+
+    #[test]
+    fn test_global_stats_counts_active_ended_and_unique_voters() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+
+        let poll1 = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Poll 1".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+        let poll2 = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Poll 2".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        contract.cast_vote(&voter, poll1, 0).unwrap();
+        contract.cast_vote(&voter, poll2, 0).unwrap();
+        contract.end_poll(&admin, poll2).unwrap();
+
+        let stats = contract.global_stats();
+        assert_eq!(stats.total_polls, 2);
+        assert_eq!(stats.active_polls, 1);
+        assert_eq!(stats.ended_polls, 1);
+        assert_eq!(stats.unique_voters, 1);
+        assert!((stats.average_turnout - 1.0).abs() < f64::EPSILON);
+        assert!(stats.to_json().contains("\"total_polls\":2"));
+    }
+
+    #[test]
+    fn test_change_vote_moves_tally_between_options() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string(), "Option B".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        contract.change_vote(&voter, poll_id, 1).unwrap();
+
+        let poll = contract.get_poll_details(poll_id).unwrap();
+        assert_eq!(poll.vote_counts, vec![0, 1]);
+        assert_eq!(poll.votes.get(&voter), Some(&1));
+
+        assert!(matches!(
+            contract.change_vote(&voter, poll_id, 5),
+            Err(VotingError::InvalidOption)
+        ));
+
+        let other_voter = create_test_address(3);
+        assert!(matches!(
+            contract.change_vote(&other_voter, poll_id, 0),
+            Err(VotingError::VoteNotFound)
+        ));
+
+        contract.end_poll(&admin, poll_id).unwrap();
+        assert!(matches!(
+            contract.change_vote(&voter, poll_id, 0),
+            Err(VotingError::PollInactive)
+        ));
+    }
+
+    #[test]
+    fn test_withdraw_vote_clears_tally() {
+        let mut contract = VotingContract::new();
+        let admin = contract.admins[0].clone();
+        let voter = create_test_address(2);
+
+        let poll_id = contract.create_poll(
+            &admin,
+            PollContent {
+                title: "Test Poll".to_string(),
+                description: "Description".to_string(),
+                options: vec!["Option A".to_string()],
+                duration: 86400,
+            },
+        ).unwrap();
+
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        contract.withdraw_vote(&voter, poll_id).unwrap();
+
+        let poll = contract.get_poll_details(poll_id).unwrap();
+        assert_eq!(poll.vote_counts, vec![0]);
+        assert!(!poll.votes.contains_key(&voter));
+
+        assert!(matches!(
+            contract.withdraw_vote(&voter, poll_id),
+            Err(VotingError::VoteNotFound)
+        ));
+
+        // A withdrawn voter can vote again since they're no longer tracked.
+        contract.cast_vote(&voter, poll_id, 0).unwrap();
+        contract.end_poll(&admin, poll_id).unwrap();
+        assert!(matches!(
+            contract.withdraw_vote(&voter, poll_id),
+            Err(VotingError::PollInactive)
+        ));
+    }
 }
\ No newline at end of file